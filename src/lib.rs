@@ -5,6 +5,9 @@ use url::Url;
 
 const BASE_URL: &str = "https://newsapi.org/v2";
 
+// Upper bound on a single backoff sleep, so exponential growth can't run away.
+const MAX_RETRY_DELAY: std::time::Duration = std::time::Duration::from_secs(60);
+
 #[derive(thiserror::Error, Debug)]
 pub enum NewsAPIError
 {
@@ -18,6 +21,26 @@ pub enum NewsAPIError
     UrlParsing(#[from] url::ParseError),
     #[error("Request failed: {0}")]
     BadRequest(&'static str),
+    #[error("Invalid API key: {0}")]
+    ApiKeyInvalid(String),
+    #[error("Missing API key: {0}")]
+    ApiKeyMissing(String),
+    #[error("API key exhausted: {0}")]
+    ApiKeyExhausted(String),
+    #[error("Invalid parameter: {0}")]
+    ParameterInvalid(String),
+    #[error("Missing parameters: {0}")]
+    ParametersMissing(String),
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+    #[error("Too many sources requested: {0}")]
+    SourcesTooMany(String),
+    #[error("Source does not exist: {0}")]
+    SourceDoesNotExist(String),
+    #[error("Maximum results reached: {0}")]
+    MaximumResultsReached(String),
+    #[error("API error ({code}): {message}")]
+    ApiError { code: String, message: String },
     #[error("Async request failed")]
     #[cfg(feature = "async")]
     AsyncRequestFailed(#[from] reqwest::Error)
@@ -28,29 +51,79 @@ pub struct NewsAPIResponse
 {
     status: String,
     articles: Vec<Article>,
-    code: Option<String>
+    code: Option<String>,
+    message: Option<String>,
+    #[serde(rename = "totalResults")]
+    total_results: Option<usize>
 }
 
 impl NewsAPIResponse
 {
-    // getter
+    // getters
     pub fn articles(&self) -> &Vec<Article>
     {
         &self.articles
     }
+
+    pub fn total_results(&self) -> Option<usize>
+    {
+        self.total_results
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Source
+{
+    id: Option<String>,
+    name: String
+}
+
+impl Source
+{
+    // getters
+    pub fn id(&self) -> Option<&String>
+    {
+        self.id.as_ref()
+    }
+
+    pub fn name(&self) -> &str
+    {
+        &self.name
+    }
 }
 
 #[derive(Deserialize, Debug)]
 pub struct Article
 {
+    source: Source,
+    author: Option<String>,
     title: String,
     url: String,
-    description: Option<String>
+    description: Option<String>,
+    #[serde(rename = "urlToImage")]
+    url_to_image: Option<String>,
+    #[cfg(feature = "chrono")]
+    #[serde(rename = "publishedAt", deserialize_with = "deserialize_published_at")]
+    published_at: chrono::DateTime<chrono::Utc>,
+    #[cfg(not(feature = "chrono"))]
+    #[serde(rename = "publishedAt")]
+    published_at: String,
+    content: Option<String>
 }
 
 impl Article
 {
     // getters
+    pub fn source(&self) -> &Source
+    {
+        &self.source
+    }
+
+    pub fn author(&self) -> Option<&String>
+    {
+        self.author.as_ref()
+    }
+
     pub fn title(&self) -> &str
     {
         &self.title
@@ -65,11 +138,46 @@ impl Article
     {
         self.description.as_ref()
     }
+
+    pub fn url_to_image(&self) -> Option<&String>
+    {
+        self.url_to_image.as_ref()
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn published_at(&self) -> &chrono::DateTime<chrono::Utc>
+    {
+        &self.published_at
+    }
+
+    #[cfg(not(feature = "chrono"))]
+    pub fn published_at(&self) -> &str
+    {
+        &self.published_at
+    }
+
+    pub fn content(&self) -> Option<&String>
+    {
+        self.content.as_ref()
+    }
+}
+
+// NewsAPI stamps `publishedAt` as RFC 3339; normalize it to UTC.
+#[cfg(feature = "chrono")]
+fn deserialize_published_at<'de, D>(deserializer: D) -> Result<chrono::DateTime<chrono::Utc>, D::Error>
+where
+    D: serde::Deserializer<'de>
+{
+    let raw = String::deserialize(deserializer)?;
+    chrono::DateTime::parse_from_rfc3339(&raw)
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+        .map_err(serde::de::Error::custom)
 }
 
 pub enum Endpoint
 {
-    TopHeadlines
+    TopHeadlines,
+    Everything
 }
 
 impl ToString for Endpoint
@@ -77,15 +185,36 @@ impl ToString for Endpoint
     fn to_string(&self) -> String {
         match self
         {
-            Self::TopHeadlines => "top-headlines".to_string()
+            Self::TopHeadlines => "top-headlines".to_string(),
+            Self::Everything => "everything".to_string()
+        }
+    }
+}
+
+pub enum SortBy
+{
+    Relevancy,
+    Popularity,
+    PublishedAt
+}
+
+impl ToString for SortBy
+{
+    fn to_string(&self) -> String {
+        match self
+        {
+            Self::Relevancy => "relevancy".to_string(),
+            Self::Popularity => "popularity".to_string(),
+            Self::PublishedAt => "publishedAt".to_string()
         }
     }
 }
 
 pub enum Country
 {
-    US,
-    FR
+    AE, AR, AT, AU, BE, BG, BR, CA, CH, CN, CO, CU, CZ, DE, EG, FR, GB, GR,
+    HK, HU, ID, IE, IL, IN, IT, JP, KR, LT, LV, MA, MX, MY, NG, NL, NO, NZ,
+    PH, PL, PT, RO, RS, RU, SA, SE, SG, SI, SK, TH, TR, TW, UA, US, VE, ZA
 }
 
 impl ToString for Country
@@ -93,29 +222,256 @@ impl ToString for Country
     fn to_string(&self) -> String {
         match self
         {
-            Self::US => "us".to_string(),
-            Self::FR => "fr".to_string()
+            Self::AE => "ae", Self::AR => "ar", Self::AT => "at", Self::AU => "au",
+            Self::BE => "be", Self::BG => "bg", Self::BR => "br", Self::CA => "ca",
+            Self::CH => "ch", Self::CN => "cn", Self::CO => "co", Self::CU => "cu",
+            Self::CZ => "cz", Self::DE => "de", Self::EG => "eg", Self::FR => "fr",
+            Self::GB => "gb", Self::GR => "gr", Self::HK => "hk", Self::HU => "hu",
+            Self::ID => "id", Self::IE => "ie", Self::IL => "il", Self::IN => "in",
+            Self::IT => "it", Self::JP => "jp", Self::KR => "kr", Self::LT => "lt",
+            Self::LV => "lv", Self::MA => "ma", Self::MX => "mx", Self::MY => "my",
+            Self::NG => "ng", Self::NL => "nl", Self::NO => "no", Self::NZ => "nz",
+            Self::PH => "ph", Self::PL => "pl", Self::PT => "pt", Self::RO => "ro",
+            Self::RS => "rs", Self::RU => "ru", Self::SA => "sa", Self::SE => "se",
+            Self::SG => "sg", Self::SI => "si", Self::SK => "sk", Self::TH => "th",
+            Self::TR => "tr", Self::TW => "tw", Self::UA => "ua", Self::US => "us",
+            Self::VE => "ve", Self::ZA => "za"
+        }.to_string()
+    }
+}
+
+pub enum Category
+{
+    Business,
+    Entertainment,
+    General,
+    Health,
+    Science,
+    Sports,
+    Technology
+}
+
+impl ToString for Category
+{
+    fn to_string(&self) -> String {
+        match self
+        {
+            Self::Business => "business".to_string(),
+            Self::Entertainment => "entertainment".to_string(),
+            Self::General => "general".to_string(),
+            Self::Health => "health".to_string(),
+            Self::Science => "science".to_string(),
+            Self::Sports => "sports".to_string(),
+            Self::Technology => "technology".to_string()
         }
     }
 }
 
+pub enum Language
+{
+    AR, DE, EN, ES, FR, HE, IT, NL, NO, PT, RU, SV, UD, ZH
+}
+
+impl ToString for Language
+{
+    fn to_string(&self) -> String {
+        match self
+        {
+            Self::AR => "ar", Self::DE => "de", Self::EN => "en", Self::ES => "es",
+            Self::FR => "fr", Self::HE => "he", Self::IT => "it", Self::NL => "nl",
+            Self::NO => "no", Self::PT => "pt", Self::RU => "ru", Self::SV => "sv",
+            Self::UD => "ud", Self::ZH => "zh"
+        }.to_string()
+    }
+}
+
+/// A raw transport response: the body plus the server's `Retry-After`, if any.
+pub struct HttpResponse
+{
+    pub body: String,
+    pub retry_after: Option<std::time::Duration>
+}
+
+/// Transport abstraction: fetch the raw response for a prepared URL.
+///
+/// Keeping the transport behind a trait lets response parsing and error
+/// dispatch live in exactly one place, and lets tests inject a mock. An
+/// error status (e.g. 429) is returned as an `HttpResponse` carrying the
+/// body so the caller can classify it, not as a transport error.
+#[async_trait::async_trait(?Send)]
+pub trait HttpBackend
+{
+    async fn send(&self, url: &str, api_key: &str) -> Result<HttpResponse, NewsAPIError>;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub struct UreqBackend;
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait::async_trait(?Send)]
+impl HttpBackend for UreqBackend
+{
+    async fn send(&self, url: &str, api_key: &str) -> Result<HttpResponse, NewsAPIError>
+    {
+        match ureq::get(url).set("Authorization", api_key).call()
+        {
+            Ok(resp) =>
+            {
+                let retry_after = parse_retry_after(resp.header("Retry-After"));
+                Ok(HttpResponse { body: resp.into_string()?, retry_after })
+            }
+            // NewsAPI still returns a JSON error body on 4xx/5xx; hand it back so
+            // the error is classified from the body's `code` in one place. The
+            // HTTP status itself is not passed on.
+            Err(ureq::Error::Status(_, resp)) =>
+            {
+                let retry_after = parse_retry_after(resp.header("Retry-After"));
+                Ok(HttpResponse { body: resp.into_string()?, retry_after })
+            }
+            Err(e) => Err(e.into())
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub struct ReqwestBackend;
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait(?Send)]
+impl HttpBackend for ReqwestBackend
+{
+    async fn send(&self, url: &str, api_key: &str) -> Result<HttpResponse, NewsAPIError>
+    {
+        let client = reqwest::Client::new();
+        let request = client
+            .request(Method::GET, url)
+            .header("Authorization", api_key)
+            .build()?;
+
+        let resp = client.execute(request).await?;
+        let retry_after = parse_retry_after(
+            resp.headers().get("retry-after").and_then(|v| v.to_str().ok())
+        );
+        Ok(HttpResponse { body: resp.text().await?, retry_after })
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub struct ReqwasmBackend;
+
+#[cfg(target_arch = "wasm32")]
+#[async_trait::async_trait(?Send)]
+impl HttpBackend for ReqwasmBackend
+{
+    async fn send(&self, url: &str, api_key: &str) -> Result<HttpResponse, NewsAPIError>
+    {
+        let resp = reqwasm::http::Request::get(url)
+            .header("Authorization", api_key)
+            .send()
+            .await
+            .map_err(|_| NewsAPIError::BadRequest("failed sending request"))?;
+
+        let retry_after = parse_retry_after(resp.headers().get("retry-after").as_deref());
+        let body = resp
+            .text()
+            .await
+            .map_err(|_| NewsAPIError::BadRequest("failed converting response to string"))?;
+        Ok(HttpResponse { body, retry_after })
+    }
+}
+
+fn parse_retry_after(value: Option<&str>) -> Option<std::time::Duration>
+{
+    value
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(std::time::Duration::from_secs)
+}
+
+// Runtime sleep used by the retry loop — tokio's timer with the `async` feature,
+// the browser timer on wasm, and a blocking thread sleep otherwise. The choice
+// follows the build, not the backend in use.
+#[cfg(all(feature = "async", not(target_arch = "wasm32")))]
+async fn sleep(duration: std::time::Duration)
+{
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn sleep(duration: std::time::Duration)
+{
+    gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
+}
+
+#[cfg(not(any(feature = "async", target_arch = "wasm32")))]
+async fn sleep(duration: std::time::Duration)
+{
+    std::thread::sleep(duration);
+}
+
+fn default_backend() -> Box<dyn HttpBackend>
+{
+    #[cfg(target_arch = "wasm32")]
+    {
+        Box::new(ReqwasmBackend)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Box::new(UreqBackend)
+    }
+}
+
 pub struct NewsAPI
 {
     api_key: String,
+    backend: Box<dyn HttpBackend>,
     endpoint: Endpoint,
-    country: Country
+    country: Option<Country>,
+    category: Option<Category>,
+    sources: Option<String>,
+    q: Option<String>,
+    search_in: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    domains: Option<String>,
+    exclude_domains: Option<String>,
+    language: Option<Language>,
+    sort_by: Option<SortBy>,
+    page: Option<usize>,
+    page_size: Option<usize>,
+    max_retries: u32,
+    base_delay: std::time::Duration
 }
 
 impl NewsAPI
 {
     // constructor
     pub fn new(api_key: &str) -> Self
+    {
+        Self::with_backend(api_key, default_backend())
+    }
+
+    pub fn with_backend(api_key: &str, backend: Box<dyn HttpBackend>) -> Self
     {
         Self
         {
             api_key: api_key.to_string(),
+            backend,
             endpoint: Endpoint::TopHeadlines,
-            country: Country::US
+            country: None,
+            category: None,
+            sources: None,
+            q: None,
+            search_in: None,
+            from: None,
+            to: None,
+            domains: None,
+            exclude_domains: None,
+            language: None,
+            sort_by: None,
+            page: None,
+            page_size: None,
+            max_retries: 3,
+            base_delay: std::time::Duration::from_millis(500)
         }
     }
 
@@ -128,93 +484,357 @@ impl NewsAPI
 
     pub fn country(&mut self, country: Country) -> &mut NewsAPI
     {
-        self.country = country;
+        self.country = Some(country);
+        self
+    }
+
+    pub fn category(&mut self, category: Category) -> &mut NewsAPI
+    {
+        self.category = Some(category);
+        self
+    }
+
+    pub fn sources(&mut self, sources: &str) -> &mut NewsAPI
+    {
+        self.sources = Some(sources.to_string());
+        self
+    }
+
+    pub fn q(&mut self, q: &str) -> &mut NewsAPI
+    {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn search_in(&mut self, search_in: &str) -> &mut NewsAPI
+    {
+        self.search_in = Some(search_in.to_string());
+        self
+    }
+
+    pub fn from(&mut self, from: &str) -> &mut NewsAPI
+    {
+        self.from = Some(from.to_string());
+        self
+    }
+
+    pub fn to(&mut self, to: &str) -> &mut NewsAPI
+    {
+        self.to = Some(to.to_string());
+        self
+    }
+
+    pub fn domains(&mut self, domains: &str) -> &mut NewsAPI
+    {
+        self.domains = Some(domains.to_string());
+        self
+    }
+
+    pub fn exclude_domains(&mut self, exclude_domains: &str) -> &mut NewsAPI
+    {
+        self.exclude_domains = Some(exclude_domains.to_string());
+        self
+    }
+
+    pub fn language(&mut self, language: Language) -> &mut NewsAPI
+    {
+        self.language = Some(language);
+        self
+    }
+
+    pub fn sort_by(&mut self, sort_by: SortBy) -> &mut NewsAPI
+    {
+        self.sort_by = Some(sort_by);
+        self
+    }
+
+    pub fn page(&mut self, page: usize) -> &mut NewsAPI
+    {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn page_size(&mut self, page_size: usize) -> &mut NewsAPI
+    {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn backend(&mut self, backend: Box<dyn HttpBackend>) -> &mut NewsAPI
+    {
+        self.backend = backend;
+        self
+    }
+
+    pub fn max_retries(&mut self, max_retries: u32) -> &mut NewsAPI
+    {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn base_delay(&mut self, base_delay: std::time::Duration) -> &mut NewsAPI
+    {
+        self.base_delay = base_delay;
         self
     }
 
     // other
     fn prepare_url(&self) -> Result<String, NewsAPIError>
     {
+        self.build_url(self.page)
+    }
+
+    fn build_url(&self, page: Option<usize>) -> Result<String, NewsAPIError>
+    {
+        // NewsAPI rejects `sources` alongside `country`/`category`; catch it here.
+        if self.sources.is_some() && (self.country.is_some() || self.category.is_some())
+        {
+            return Err(NewsAPIError::BadRequest(
+                "`sources` cannot be combined with `country` or `category`"
+            ));
+        }
+
         let mut url = Url::parse(BASE_URL)?;
         url.path_segments_mut().unwrap().push(&self.endpoint.to_string());
 
-        let country = format!("country={}", self.country.to_string());
-        url.set_query(Some(&country));
+        {
+            let mut query = url.query_pairs_mut();
+
+            // `country` and `category` only apply to the top-headlines endpoint
+            if let Endpoint::TopHeadlines = self.endpoint
+            {
+                if let Some(country) = &self.country
+                {
+                    query.append_pair("country", &country.to_string());
+                }
+                if let Some(category) = &self.category
+                {
+                    query.append_pair("category", &category.to_string());
+                }
+            }
+
+            if let Some(sources) = &self.sources
+            {
+                query.append_pair("sources", sources);
+            }
+            if let Some(q) = &self.q
+            {
+                query.append_pair("q", q);
+            }
+            if let Some(search_in) = &self.search_in
+            {
+                query.append_pair("searchIn", search_in);
+            }
+            if let Some(from) = &self.from
+            {
+                query.append_pair("from", from);
+            }
+            if let Some(to) = &self.to
+            {
+                query.append_pair("to", to);
+            }
+            if let Some(domains) = &self.domains
+            {
+                query.append_pair("domains", domains);
+            }
+            if let Some(exclude_domains) = &self.exclude_domains
+            {
+                query.append_pair("excludeDomains", exclude_domains);
+            }
+            if let Some(language) = &self.language
+            {
+                query.append_pair("language", &language.to_string());
+            }
+            if let Some(sort_by) = &self.sort_by
+            {
+                query.append_pair("sortBy", &sort_by.to_string());
+            }
+            if let Some(page_size) = self.page_size
+            {
+                query.append_pair("pageSize", &page_size.to_string());
+            }
+            if let Some(page) = page
+            {
+                query.append_pair("page", &page.to_string());
+            }
+        }
 
         Ok(url.to_string())
     }
 
-    pub fn fetch(&self) -> Result<NewsAPIResponse, NewsAPIError>
+    pub async fn fetch(&self) -> Result<NewsAPIResponse, NewsAPIError>
     {
         let url = self.prepare_url()?;
-        let req = ureq::get(&url).set("Authorization", &self.api_key);
-        let response: NewsAPIResponse = req.call()?.into_json()?;
-        match response.status.as_str()
+        self.fetch_with_retry(&url).await
+    }
+
+    // Issue a request, retrying rate-limit failures with exponential backoff.
+    // The retry counter resets only on success; the last typed error is returned.
+    async fn fetch_with_retry(&self, url: &str) -> Result<NewsAPIResponse, NewsAPIError>
+    {
+        let mut attempt = 0;
+        loop
         {
-            "ok" => Ok(response),
-            _ => Err(map_response_err(response.code))
+            let response = self.backend.send(url, &self.api_key).await?;
+            let retry_after = response.retry_after;
+
+            match self.parse_response(&response.body)
+            {
+                Ok(ok) => return Ok(ok),
+                Err(err) =>
+                {
+                    if attempt >= self.max_retries || !is_retryable(&err)
+                    {
+                        return Err(err);
+                    }
+
+                    // Prefer the server's Retry-After over our computed backoff.
+                    let delay = retry_after.unwrap_or_else(|| self.backoff_delay(attempt));
+                    sleep(delay).await;
+                    attempt += 1;
+                }
+            }
         }
     }
 
-    #[cfg(feature = "async")]
-    pub async fn fetch_async(&self) -> Result<NewsAPIResponse, NewsAPIError>
+    // delay = base_delay * 2^attempt, capped at MAX_RETRY_DELAY.
+    fn backoff_delay(&self, attempt: u32) -> std::time::Duration
     {
-        let url = self.prepare_url()?;
-        let client = reqwest::Client::new();
-        let request = client
-            .request(Method::GET, url)
-            .header("Authorization", &self.api_key)
-            .build()
-            .map_err(|e| NewsAPIError::AsyncRequestFailed(e))?;
-
-        let response: NewsAPIResponse = client
-            .execute(request)
-            .await?
-            .json()
-            .await
-            .map_err(|e| NewsAPIError::AsyncRequestFailed(e))?;
+        let factor = 2u32.saturating_pow(attempt);
+        self.base_delay.saturating_mul(factor).min(MAX_RETRY_DELAY)
+    }
 
+    // Deserialize a raw response body and dispatch server-reported errors.
+    fn parse_response(&self, body: &str) -> Result<NewsAPIResponse, NewsAPIError>
+    {
+        let mut value: serde_json::Value = serde_json::from_str(body)?;
+        // Error bodies carry no `articles`; default it so they still reach the dispatch below.
+        if let Some(object) = value.as_object_mut()
+        {
+            object.entry("articles").or_insert_with(|| serde_json::Value::Array(Vec::new()));
+        }
+        let response: NewsAPIResponse = serde_json::from_value(value)?;
         match response.status.as_str()
         {
             "ok" => Ok(response),
-            _ => Err(map_response_err(response.code))
+            _ => Err(map_response_err(response.code, response.message))
         }
     }
 
-    #[cfg(target_arch = "wasm32")]
-    pub async fn fetch_web(&self) -> Result<NewsAPIResponse, NewsAPIError>
+    // Fetch a single page, overriding any `page` setter with the given page number.
+    #[cfg(feature = "async")]
+    async fn fetch_page_async(&self, page: usize, page_size: usize) -> Result<NewsAPIResponse, NewsAPIError>
     {
-        let url = self.prepare_url()?;
-        let req = reqwasm::http::Request::get(&url).header("Authorization", &self.api_key);
-        let resp = req
-            .send()
-            .await
-            .map_err(|_| NewsAPIError::BadRequest("failed sending request"))?;
+        let mut url = Url::parse(&self.build_url(Some(page))?)?;
+        url.query_pairs_mut().append_pair("pageSize", &page_size.to_string());
+        let url = url.to_string();
+        self.fetch_with_retry(&url).await
+    }
 
-        let response: NewsAPIResponse = resp
-            .json()
-            .await
-            .map_err(|_| NewsAPIError::BadRequest("failed converting response to json"))?;
+    // Auto-paging stream: yields up to `max` articles, issuing requests with an
+    // incrementing `page` until `max` is reached or `total_results` is exhausted.
+    #[cfg(feature = "async")]
+    pub fn fetch_all_async(&self, max: usize) -> impl futures::Stream<Item = Result<Article, NewsAPIError>> + '_
+    {
+        let page_size = self.page_size.unwrap_or(100);
+        let state = AutoPageState
+        {
+            api: self,
+            page: 1,
+            page_size,
+            emitted: 0,
+            buffer: std::collections::VecDeque::new(),
+            finished: false
+        };
 
-        match response.status.as_str() {
-            "ok" => return Ok(response),
-            _ => return Err(map_response_err(response.code)),
-        }
+        futures::stream::unfold(state, move |mut state| async move {
+            loop
+            {
+                if state.emitted >= max
+                {
+                    return None;
+                }
+                if let Some(article) = state.buffer.pop_front()
+                {
+                    state.emitted += 1;
+                    return Some((Ok(article), state));
+                }
+                if state.finished
+                {
+                    return None;
+                }
+
+                match state.api.fetch_page_async(state.page, state.page_size).await
+                {
+                    Ok(response) =>
+                    {
+                        let total = response.total_results.unwrap_or(0);
+                        let count = response.articles.len();
+                        state.buffer.extend(response.articles);
+
+                        // Stop once a short page arrives or we've covered every result.
+                        if count < state.page_size || state.page * state.page_size >= total
+                        {
+                            state.finished = true;
+                        }
+                        state.page += 1;
+
+                        if state.buffer.is_empty()
+                        {
+                            return None;
+                        }
+                    }
+                    Err(err) =>
+                    {
+                        // Surface the error once, then end the stream rather than loop.
+                        state.finished = true;
+                        return Some((Err(err), state));
+                    }
+                }
+            }
+        })
     }
 }
 
-fn map_response_err(code: Option<String>) -> NewsAPIError
+#[cfg(feature = "async")]
+struct AutoPageState<'a>
+{
+    api: &'a NewsAPI,
+    page: usize,
+    page_size: usize,
+    emitted: usize,
+    buffer: std::collections::VecDeque<Article>,
+    finished: bool
+}
+
+// A throttling error worth retrying: HTTP 429 surfaces as `rateLimited`, and an
+// exhausted key may recover once its window rolls over.
+fn is_retryable(err: &NewsAPIError) -> bool
+{
+    matches!(err, NewsAPIError::RateLimited(_) | NewsAPIError::ApiKeyExhausted(_))
+}
+
+fn map_response_err(code: Option<String>, message: Option<String>) -> NewsAPIError
 {
+    let message = message.unwrap_or_else(|| "Unknown error".to_string());
     if let Some(code) = code
     {
         match code.as_str()
         {
-            "apiKeyDisabled" => NewsAPIError::BadRequest("Your API key has been disabled"),
-            _ => NewsAPIError::BadRequest("Unknown error")
+            "apiKeyInvalid" => NewsAPIError::ApiKeyInvalid(message),
+            "apiKeyMissing" => NewsAPIError::ApiKeyMissing(message),
+            "apiKeyExhausted" => NewsAPIError::ApiKeyExhausted(message),
+            "parameterInvalid" => NewsAPIError::ParameterInvalid(message),
+            "parametersMissing" => NewsAPIError::ParametersMissing(message),
+            "rateLimited" => NewsAPIError::RateLimited(message),
+            "sourcesTooMany" => NewsAPIError::SourcesTooMany(message),
+            "sourceDoesNotExist" => NewsAPIError::SourceDoesNotExist(message),
+            "maximumResultsReached" => NewsAPIError::MaximumResultsReached(message),
+            _ => NewsAPIError::ApiError { code, message }
         }
     }
     else
     {
-        NewsAPIError::BadRequest("Unknown error")
+        NewsAPIError::ApiError { code: "unknown".to_string(), message }
     }
 }
\ No newline at end of file